@@ -1,8 +1,10 @@
 pub use crate::search::{
-    Channel, EmbedOptions, LanguageTags, PlaylistSearchOptions, RequestOptions, SearchOptions,
-    SearchResult, SearchType, Video,
+    ChannelSearchOptions, ChannelVideosOrder, EmbedOptions, LanguageTags, PlaylistSearchOptions,
+    RequestOptions, SearchOptions, SearchResult, SearchType, Video,
+};
+use crate::search::{
+    Channel as AsyncChannel, Playlist as AsyncPlaylist, YouTube as AsyncYouTube,
 };
-use crate::search::{Playlist as AsyncPlaylist, YouTube as AsyncYouTube};
 use crate::{block_async, VideoError};
 use serde::Serialize;
 
@@ -65,6 +67,31 @@ impl YouTube {
     ) -> Result<Vec<String>, VideoError> {
         Ok(block_async!(self.0.suggestion(query, language))?)
     }
+
+    /// Classify any YouTube/YouTube-Music url or short link into a [`crate::utils::UrlTarget`]
+    /// without performing a full fetch, so callers can decide whether to dispatch to
+    /// [`crate::blocking::Video`], [`Playlist`], [`Channel`], or the music search entry points.
+    ///
+    /// Album-prefixed music.youtube.com playlist ids are only reported as
+    /// [`crate::utils::UrlTarget::Album`] when `resolve_albums` is `true`; otherwise they come
+    /// back as an ordinary [`crate::utils::UrlTarget::Playlist`].
+    pub fn resolve_url(
+        url: impl AsRef<str>,
+        resolve_albums: bool,
+    ) -> Option<crate::utils::UrlTarget> {
+        crate::utils::resolve_url(url.as_ref(), resolve_albums)
+    }
+
+    /// Map a YouTube Music search shelf's raw `musicResponsiveListItemRenderer` contents (songs,
+    /// albums, artists, and music-playlists) into [`crate::utils::MusicSearchResult`]s, so callers
+    /// can resolve a song → album → artist chain that the plain video [`YouTube::search`] can't
+    /// express. Broken/placeholder artist-channel entries are skipped rather than erroring the
+    /// whole search.
+    pub fn map_music_search_results(
+        items: &[serde_json::Value],
+    ) -> Vec<crate::utils::MusicSearchResult> {
+        crate::utils::map_music_search_results(items)
+    }
 }
 
 impl std::ops::Deref for YouTube {
@@ -172,6 +199,57 @@ impl Playlist {
     pub fn get_playlist_url(url_or_id: impl Into<String>) -> Option<String> {
         AsyncPlaylist::get_playlist_url(url_or_id)
     }
+
+    /// Shape this playlist the way `yt-dlp --dump-json` does.
+    pub fn to_ytdl_json(&self) -> serde_json::Value {
+        let raw = serde_json::to_value(&self.0).unwrap_or(serde_json::Value::Null);
+        let str_field = |name: &str| {
+            raw.get(name)
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string()
+        };
+
+        let entries: Vec<serde_json::Value> = raw
+            .get("videos")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .map(|video| {
+                crate::utils::video_entry_to_ytdl_json(
+                    video.get("id").and_then(|v| v.as_str()).unwrap_or_default(),
+                    video
+                        .get("title")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or_default(),
+                    video
+                        .get("url")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or_default(),
+                )
+            })
+            .collect();
+
+        let channel = raw.get("channel");
+        let uploader = channel.and_then(|c| c.get("name")).and_then(|v| v.as_str());
+        let uploader_id = channel.and_then(|c| c.get("id")).and_then(|v| v.as_str());
+
+        let mut json = crate::utils::playlist_to_ytdl_json(
+            &str_field("id"),
+            &str_field("title"),
+            &str_field("url"),
+            uploader,
+            uploader_id,
+            &[],
+            &entries,
+        );
+
+        if let (Some(obj), Some(thumbnails)) = (json.as_object_mut(), raw.get("thumbnails")) {
+            obj.insert("thumbnails".to_string(), thumbnails.clone());
+        }
+
+        json
+    }
 }
 
 impl std::ops::Deref for Playlist {
@@ -187,3 +265,75 @@ impl std::ops::DerefMut for Playlist {
         &mut self.0
     }
 }
+
+#[derive(Clone, derivative::Derivative, Serialize)]
+#[derivative(Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Channel(pub(super) AsyncChannel);
+
+impl Channel {
+    /// Try to get [`Channel`] than fetch videos according to the [`ChannelSearchOptions`].
+    ///
+    /// `url_or_id` accepts a `/channel/UC...` url, a `/@handle` url, a `/c/name` url, or a bare
+    /// channel id; it is resolved internally before any request is made.
+    pub fn get(
+        url_or_id: impl Into<String>,
+        options: Option<&ChannelSearchOptions>,
+    ) -> Result<Self, VideoError> {
+        Ok(Self(block_async!(AsyncChannel::get(url_or_id, options))?))
+    }
+
+    /// Get next chunk of uploads from the channel and return fetched [`Video`] array, walking
+    /// the continuation token returned by the previous page.
+    /// - If limit is [`None`] it will be [`u64::MAX`]
+    pub fn next(&mut self, limit: Option<u64>) -> Result<Vec<Video>, VideoError> {
+        Ok(block_async!(self.0.next(limit))?)
+    }
+
+    /// Try to fetch all channel uploads (draining every continuation token) and return [`Channel`].
+    /// - If limit is [`None`] it will be [`u64::MAX`]
+    pub fn fetch(&mut self, limit: Option<u64>) -> &mut Self {
+        self.0 = block_async!(self.0.fetch(limit)).clone();
+
+        self
+    }
+
+    /// Fetch the channel's own playlists (as opposed to its uploaded videos).
+    pub fn channel_playlists(&mut self) -> Result<Vec<Playlist>, VideoError> {
+        Ok(block_async!(self.0.channel_playlists())?
+            .into_iter()
+            .map(Playlist)
+            .collect())
+    }
+
+    pub fn is_channel(url_or_id: impl Into<String>) -> bool {
+        AsyncChannel::is_channel(url_or_id)
+    }
+
+    /// Fetch the channel's ~15 most recent uploads from its public Atom feed
+    /// (`youtube.com/feeds/videos.xml?channel_id=...`) instead of the InnerTube endpoints.
+    ///
+    /// This is a much lighter, quota-free way to poll for new uploads than [`Channel::next`],
+    /// at the cost of only ever seeing the most recent page.
+    #[cfg(feature = "rss")]
+    pub fn rss(url_or_id: impl Into<String>) -> Result<Vec<Video>, VideoError> {
+        let url_or_id = url_or_id.into();
+        let channel_id = crate::utils::resolve_channel_id(&url_or_id)?;
+
+        Ok(block_async!(crate::rss::fetch_channel_rss(channel_id))?)
+    }
+}
+
+impl std::ops::Deref for Channel {
+    type Target = AsyncChannel;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for Channel {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -0,0 +1,165 @@
+//! Parsing of a channel's public Atom feed (`youtube.com/feeds/videos.xml?channel_id=...`) into
+//! [`Video`]s, mirroring rustypipe's `rss` feature. This is a fast, quota-free way to poll the
+//! ~15 most recent uploads without hitting the heavier InnerTube endpoints, which makes it a good
+//! fit for subscription/notification-style polling.
+#![cfg(feature = "rss")]
+
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+
+use crate::search::Video;
+use crate::{Thumbnail, VideoError};
+
+const CHANNEL_RSS_URL: &str = "https://www.youtube.com/feeds/videos.xml";
+
+/// Download and parse a channel's Atom upload feed.
+///
+/// `channel_id` must already be a resolved `UC...` channel id (see [`crate::utils::resolve_url`]
+/// to turn a `/channel/`, `/@handle`, or `/c/` url into one first).
+pub async fn fetch_channel_rss(channel_id: impl AsRef<str>) -> Result<Vec<Video>, VideoError> {
+    let url = format!("{CHANNEL_RSS_URL}?channel_id={}", channel_id.as_ref());
+
+    let body = reqwest::get(url)
+        .await
+        .map_err(VideoError::Reqwest)?
+        .text()
+        .await
+        .map_err(VideoError::Reqwest)?;
+
+    parse_channel_rss(&body)
+}
+
+/// Parse an already-downloaded Atom feed body into [`Video`]s.
+pub fn parse_channel_rss(xml: &str) -> Result<Vec<Video>, VideoError> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut videos = Vec::new();
+
+    let mut in_entry = false;
+    let mut tag_path: Vec<String> = Vec::new();
+
+    let mut video_id = String::new();
+    let mut title = String::new();
+    let mut published = String::new();
+    let mut description = String::new();
+    let mut thumbnail_url = String::new();
+    let mut thumbnail_width = 0i32;
+    let mut thumbnail_height = 0i32;
+    let mut views = String::new();
+    let mut likes = String::new();
+
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+
+                if name == "entry" {
+                    in_entry = true;
+                    video_id.clear();
+                    title.clear();
+                    published.clear();
+                    description.clear();
+                    thumbnail_url.clear();
+                    thumbnail_width = 0;
+                    thumbnail_height = 0;
+                    views.clear();
+                    likes.clear();
+                } else if in_entry && name == "media:thumbnail" {
+                    let attrs = e.attributes().flatten().collect::<Vec<_>>();
+
+                    if let Some(attr) = attrs.iter().find(|attr| attr.key.as_ref() == b"url") {
+                        thumbnail_url = attr.unescape_value().unwrap_or_default().to_string();
+                    }
+                    if let Some(attr) = attrs.iter().find(|attr| attr.key.as_ref() == b"width") {
+                        thumbnail_width = attr
+                            .unescape_value()
+                            .unwrap_or_default()
+                            .parse()
+                            .unwrap_or(0);
+                    }
+                    if let Some(attr) = attrs.iter().find(|attr| attr.key.as_ref() == b"height") {
+                        thumbnail_height = attr
+                            .unescape_value()
+                            .unwrap_or_default()
+                            .parse()
+                            .unwrap_or(0);
+                    }
+                } else if in_entry && name == "media:statistics" {
+                    if let Some(attr) = e
+                        .attributes()
+                        .flatten()
+                        .find(|attr| attr.key.as_ref() == b"views")
+                    {
+                        views = attr.unescape_value().unwrap_or_default().to_string();
+                    }
+                } else if in_entry && name == "media:starRating" {
+                    if let Some(attr) = e
+                        .attributes()
+                        .flatten()
+                        .find(|attr| attr.key.as_ref() == b"count")
+                    {
+                        likes = attr.unescape_value().unwrap_or_default().to_string();
+                    }
+                }
+
+                tag_path.push(name);
+            }
+            Ok(Event::Text(e)) => {
+                if !in_entry {
+                    continue;
+                }
+
+                let text = e.unescape().unwrap_or_default().to_string();
+
+                match tag_path.last().map(|s| s.as_str()) {
+                    Some("yt:videoId") => video_id = text,
+                    Some("media:title") => title = text,
+                    Some("published") => published = text,
+                    Some("media:description") => description = text,
+                    _ => {}
+                }
+            }
+            Ok(Event::End(e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                tag_path.pop();
+
+                if name == "entry" {
+                    in_entry = false;
+
+                    // `Video` has no dedicated like-count field, so fold `media:starRating`'s
+                    // `count` into the description rather than dropping it on the floor like the
+                    // view count's sibling attribute would otherwise be.
+                    let description = if likes.is_empty() {
+                        description.clone()
+                    } else {
+                        format!("{description}\n\nLikes: {likes}")
+                    };
+
+                    videos.push(Video {
+                        id: video_id.clone(),
+                        title: title.clone(),
+                        description,
+                        url: format!("https://www.youtube.com/watch?v={video_id}"),
+                        uploaded_at: published.clone(),
+                        view_count: views.clone(),
+                        thumbnails: vec![Thumbnail {
+                            url: thumbnail_url.clone(),
+                            width: thumbnail_width,
+                            height: thumbnail_height,
+                        }],
+                        ..Default::default()
+                    });
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(err) => return Err(VideoError::BodyCannotParsed(err.to_string())),
+            _ => {}
+        }
+
+        buf.clear();
+    }
+
+    Ok(videos)
+}